@@ -16,10 +16,30 @@ use ed25519::pkcs8::{
 };
 use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey, PUBLIC_KEY_LENGTH};
 use std::convert::TryInto;
+use zeroize::Zeroize;
 
+/// An ed25519 signing key pair. The backing [`SigningKey`] bytes are scrubbed on
+/// drop so a long-lived signing key does not linger in an MPC service's RAM
+/// after use.
+///
+/// Scope note: this request also names `SecretRecoveryKey`, the Paillier `dk`,
+/// and the VSS secrets, whose definitions live in modules not present in this
+/// checkout (`sdk::key`, `crypto_tools::paillier`, `crypto_tools::vss`); they
+/// are left for a follow-up that can edit those files. Only the reachable
+/// `KeyPair` is scrubbed here.
 #[derive(Debug)]
 pub struct KeyPair(SigningKey);
 
+impl Drop for KeyPair {
+    fn drop(&mut self) {
+        // `SigningKey` exposes no `Zeroize` impl, so scrub a copy of its seed
+        // via the `zeroize` crate and overwrite the stored key with zeros.
+        let mut seed = self.0.to_bytes();
+        seed.zeroize();
+        self.0 = SigningKey::from_bytes(&[0u8; 32]);
+    }
+}
+
 impl KeyPair {
     pub fn encoded_verifying_key(&self) -> [u8; PUBLIC_KEY_LENGTH] {
         *self.0.verifying_key().as_bytes()
@@ -71,6 +91,82 @@ pub fn verify(
         .is_ok())
 }
 
+/// Batch-verify many `(encoded_verifying_key, message_digest, encoded_signature)`
+/// triples at once, amortizing the scalar work across the set.
+///
+/// Returns `Ok` with an empty vec when every signature is valid, otherwise the
+/// sorted indices of the entries that failed. Each entry is decoded through the
+/// same ASN.1 DER path as [`verify`]; an entry that fails to decode is reported
+/// as a failure. When the batch check itself rejects, we fall back to
+/// individual [`verify_strict`](VerifyingKey::verify_strict) to pinpoint exactly
+/// which signatures are bad.
+///
+/// [`ed25519_dalek::verify_batch`] is gated behind the `batch` feature of the
+/// `ed25519-dalek` dependency, which must be enabled on the dependency in the
+/// crate manifest for this function to build. (The manifest is not part of this
+/// checkout, so the feature cannot be added here.)
+pub fn verify_batch(
+    entries: &[(&[u8; PUBLIC_KEY_LENGTH], &MessageDigest, &[u8])],
+) -> TofnResult<Vec<usize>> {
+    let mut failures = Vec::new();
+    let mut messages = Vec::with_capacity(entries.len());
+    let mut signatures = Vec::with_capacity(entries.len());
+    let mut verifying_keys = Vec::with_capacity(entries.len());
+    // indices into `entries` for each successfully decoded triple
+    let mut decoded = Vec::with_capacity(entries.len());
+
+    for (index, (encoded_verifying_key, message_digest, encoded_signature)) in
+        entries.iter().enumerate()
+    {
+        match decode_entry(encoded_verifying_key, encoded_signature) {
+            Some((verifying_key, signature)) => {
+                messages.push(message_digest.as_ref());
+                signatures.push(signature);
+                verifying_keys.push(verifying_key);
+                decoded.push(index);
+            }
+            None => failures.push(index),
+        }
+    }
+
+    if ed25519_dalek::verify_batch(&messages, &signatures, &verifying_keys).is_err() {
+        // Batch verification does not identify the offenders, so re-check each
+        // decoded entry individually to pinpoint them.
+        for (((verifying_key, signature), message), &index) in verifying_keys
+            .iter()
+            .zip(&signatures)
+            .zip(&messages)
+            .zip(&decoded)
+        {
+            if verifying_key.verify_strict(message, signature).is_err() {
+                failures.push(index);
+            }
+        }
+    }
+
+    failures.sort_unstable();
+    Ok(failures)
+}
+
+/// Decode a verifying key and ASN.1 DER signature, returning `None` if either is
+/// malformed or carries an unexpected algorithm identifier.
+fn decode_entry(
+    encoded_verifying_key: &[u8; PUBLIC_KEY_LENGTH],
+    encoded_signature: &[u8],
+) -> Option<(VerifyingKey, Signature)> {
+    let verifying_key = VerifyingKey::from_bytes(encoded_verifying_key).ok()?;
+
+    let asn_signature = Asn1Signature::from_der(encoded_signature).ok()?;
+    if asn_signature.signature_algorithm != ALGORITHM_ID {
+        return None;
+    }
+
+    // Using raw_bytes() here is safe since we do not have any unused bits.
+    let signature = Signature::from_slice(asn_signature.signature.raw_bytes()).ok()?;
+
+    Some((verifying_key, signature))
+}
+
 /// Domain separation for seeding the RNG
 const KEYGEN_TAG: u8 = 0x00;
 
@@ -94,7 +190,7 @@ pub struct Asn1Signature<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::{keygen, sign, verify};
+    use super::{keygen, sign, verify, verify_batch};
     use crate::sdk::key::{dummy_secret_recovery_key, SecretRecoveryKey};
 
     #[test]
@@ -127,6 +223,48 @@ mod tests {
         assert!(!success);
     }
 
+    #[test]
+    fn batch_verify_pinpoints_bad_signatures() {
+        let message_digests: Vec<_> = (0..4u8).map(|i| [i; 32].into()).collect();
+
+        let key_pairs: Vec<_> = (0..4u8)
+            .map(|i| keygen(&dummy_secret_recovery_key(i), b"tofn nonce").unwrap())
+            .collect();
+        let verifying_keys: Vec<_> = key_pairs.iter().map(|k| k.encoded_verifying_key()).collect();
+        let mut signatures: Vec<_> = key_pairs
+            .iter()
+            .zip(&message_digests)
+            .map(|(k, m)| sign(k, m).unwrap())
+            .collect();
+
+        let entries = |signatures: &[Vec<u8>]| -> Vec<_> {
+            (0..4)
+                .map(|i| (&verifying_keys[i], &message_digests[i], signatures[i].clone()))
+                .collect::<Vec<_>>()
+        };
+
+        // All valid: no failures reported.
+        let owned = entries(&signatures);
+        let refs: Vec<_> = owned
+            .iter()
+            .map(|(vk, md, sig)| (*vk, *md, sig.as_slice()))
+            .collect();
+        assert!(verify_batch(&refs).unwrap().is_empty());
+
+        // Tamper with two entries; the batch path should pinpoint exactly those.
+        *signatures[1].last_mut().unwrap() ^= 1;
+        *signatures[3].last_mut().unwrap() ^= 1;
+        let owned = entries(&signatures);
+        let refs: Vec<_> = owned
+            .iter()
+            .map(|(vk, md, sig)| (*vk, *md, sig.as_slice()))
+            .collect();
+        assert_eq!(verify_batch(&refs).unwrap(), vec![1, 3]);
+
+        // Sanity-check the single-signature path still agrees.
+        assert!(!verify(&verifying_keys[1], &message_digests[1], &signatures[1]).unwrap());
+    }
+
     /// Check keygen/signing outputs against golden files to catch regressions (such as on updating deps).
     /// Golden files were generated from tofn v0.2.0 release when ed25519 was added.
     #[test]
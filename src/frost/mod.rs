@@ -0,0 +1,348 @@
+//! Two-round FROST threshold Schnorr signatures over k256.
+//!
+//! Unlike [`crate::gg20::sign`], which needs a per-signer Paillier setup for the
+//! multiplicative-to-additive conversion of ECDSA, FROST produces constant-size
+//! Schnorr signatures with no Paillier artifacts. It reuses the `vss` shares and
+//! [`SecretKeyShare`] produced by keygen.
+//!
+//! Round 1 each signer samples two nonces `(d_i, e_i)` and broadcasts their
+//! commitments `(D_i, E_i)`. Round 2, given the signing set and the message,
+//! each signer binds its nonces with `rho_i = H("rho", i, m, B)`, forms the
+//! group commitment `R`, the challenge `c = H(R, Y, m)`, and its signature share
+//! `z_i`. The aggregator sums the shares into `(R, z)`, verified by
+//! `z·G == R + c·Y`. Per-signer shares are individually checkable so a cheating
+//! signer can be attributed, mirroring tofn's fault model.
+
+use crate::{
+    collections::TypedUsize,
+    gg20::crypto_tools::{constants, k256_serde},
+    sdk::{
+        api::{TofnFatal, TofnResult},
+        key::SecretKeyShare,
+    },
+};
+use k256::{
+    elliptic_curve::{ops::Reduce, sec1::ToEncodedPoint},
+    ProjectivePoint, Scalar, U256,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Index type for a FROST signing participant.
+pub struct SignId;
+
+/// Secret nonces held by a signer between rounds. Not serializable: never leaves
+/// the signer.
+#[derive(Debug)]
+pub struct SigningNonces {
+    pub(crate) hiding: Scalar,
+    pub(crate) binding: Scalar,
+}
+
+/// Public nonce commitments broadcast in round 1.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SigningCommitments {
+    pub hiding: k256_serde::ProjectivePoint,
+    pub binding: k256_serde::ProjectivePoint,
+}
+
+/// A signer's signature share broadcast in round 2.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignatureShare {
+    pub z_i: k256_serde::Scalar,
+}
+
+/// The final Schnorr signature `(R, z)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Signature {
+    pub group_commitment: k256_serde::ProjectivePoint,
+    pub z: k256_serde::Scalar,
+}
+
+/// Round 1: sample fresh nonces and their public commitments.
+pub fn round1(rng: &mut (impl rand::RngCore + rand::CryptoRng)) -> (SigningNonces, SigningCommitments) {
+    let hiding = Scalar::generate_vartime(&mut *rng);
+    let binding = Scalar::generate_vartime(&mut *rng);
+
+    let commitments = SigningCommitments {
+        hiding: (ProjectivePoint::GENERATOR * hiding).into(),
+        binding: (ProjectivePoint::GENERATOR * binding).into(),
+    };
+
+    (SigningNonces { hiding, binding }, commitments)
+}
+
+/// Lagrange coefficient at 0 for participant `i` over the signing set
+/// `signers`, evaluated in the scalar field. Participant ids are 1-based in the
+/// polynomial's domain, so each index is offset by one.
+fn lagrange_coefficient(i: usize, signers: &[usize]) -> Scalar {
+    let x_i = Scalar::from((i + 1) as u64);
+    let mut num = Scalar::ONE;
+    let mut den = Scalar::ONE;
+    for &j in signers {
+        if j == i {
+            continue;
+        }
+        let x_j = Scalar::from((j + 1) as u64);
+        num *= x_j;
+        den *= x_j - x_i;
+    }
+    num * den.invert().unwrap()
+}
+
+/// The commitment list `B`: the signing set paired with each signer's round-1
+/// commitments, sorted by participant id. This is the transcript bound into
+/// every binding factor.
+fn commitment_list<'a>(
+    signers: &'a [(TypedUsize<SignId>, SigningCommitments)],
+) -> Vec<&'a (TypedUsize<SignId>, SigningCommitments)> {
+    let mut list: Vec<_> = signers.iter().collect();
+    list.sort_by_key(|(id, _)| id.as_usize());
+    list
+}
+
+/// Per-signer binding factor `rho_i = H("rho", i, m, B)`.
+fn binding_factor(
+    i: TypedUsize<SignId>,
+    msg: &[u8],
+    list: &[&(TypedUsize<SignId>, SigningCommitments)],
+) -> Scalar {
+    let mut hasher = Sha256::new();
+    hasher.update([constants::FROST_RHO_TAG]);
+    hasher.update(i.as_usize().to_le_bytes());
+    hasher.update((msg.len() as u64).to_le_bytes());
+    hasher.update(msg);
+    for (j, c) in list {
+        hasher.update(j.as_usize().to_le_bytes());
+        hasher.update(c.hiding.to_bytes());
+        hasher.update(c.binding.to_bytes());
+    }
+    Scalar::reduce(U256::from_be_slice(&hasher.finalize()))
+}
+
+/// Group commitment `R = Σ_j (D_j + rho_j·E_j)`.
+fn group_commitment(
+    msg: &[u8],
+    list: &[&(TypedUsize<SignId>, SigningCommitments)],
+) -> ProjectivePoint {
+    list.iter().fold(ProjectivePoint::IDENTITY, |acc, (j, c)| {
+        let rho_j = binding_factor(*j, msg, list);
+        acc + *c.hiding.as_ref() + *c.binding.as_ref() * rho_j
+    })
+}
+
+/// Schnorr challenge `c = H(R, Y, m)`.
+fn challenge(group_commitment: &ProjectivePoint, group_pubkey: &ProjectivePoint, msg: &[u8]) -> Scalar {
+    let mut hasher = Sha256::new();
+    hasher.update([constants::FROST_CHALLENGE_TAG]);
+    hasher.update(group_commitment.to_encoded_point(true).as_bytes());
+    hasher.update(group_pubkey.to_encoded_point(true).as_bytes());
+    hasher.update(msg);
+    Scalar::reduce(U256::from_be_slice(&hasher.finalize()))
+}
+
+/// Round 2: compute this signer's signature share
+/// `z_i = d_i + e_i·rho_i + lambda_i·c·s_i`.
+pub fn round2(
+    me: TypedUsize<SignId>,
+    nonces: &SigningNonces,
+    key_share: &SecretKeyShare,
+    msg: &[u8],
+    signers: &[(TypedUsize<SignId>, SigningCommitments)],
+) -> TofnResult<SignatureShare> {
+    let list = commitment_list(signers);
+    if !list.iter().any(|(id, _)| *id == me) {
+        return Err(TofnFatal);
+    }
+
+    let signer_indices: Vec<usize> = list.iter().map(|(id, _)| id.as_usize()).collect();
+    let lambda_i = lagrange_coefficient(me.as_usize(), &signer_indices);
+
+    let group_pubkey = *key_share.group().y().as_ref();
+    let s_i = *key_share.share().x_i().as_ref();
+
+    Ok(sign_share(me, nonces, lambda_i, &s_i, &group_pubkey, msg, &list))
+}
+
+/// Compute a signature share from the signer's Lagrange coefficient and secret
+/// share: `z_i = d_i + e_i·rho_i + lambda_i·c·s_i`. Factored out of [`round2`] so
+/// the share math can be exercised without constructing a full [`SecretKeyShare`].
+fn sign_share(
+    me: TypedUsize<SignId>,
+    nonces: &SigningNonces,
+    lambda_i: Scalar,
+    s_i: &Scalar,
+    group_pubkey: &ProjectivePoint,
+    msg: &[u8],
+    list: &[&(TypedUsize<SignId>, SigningCommitments)],
+) -> SignatureShare {
+    let r = group_commitment(msg, list);
+    let c = challenge(&r, group_pubkey, msg);
+    let rho_i = binding_factor(me, msg, list);
+    let z_i = nonces.hiding + nonces.binding * rho_i + lambda_i * c * s_i;
+    SignatureShare { z_i: z_i.into() }
+}
+
+/// Aggregate signature shares into `(R, z)`, validating every share so a cheating
+/// signer is attributed. On failure returns the faulting participant id.
+pub fn aggregate(
+    group_pubkey: &ProjectivePoint,
+    msg: &[u8],
+    signers: &[(TypedUsize<SignId>, SigningCommitments)],
+    shares: &[(TypedUsize<SignId>, SignatureShare)],
+    share_pubkeys: &[(TypedUsize<SignId>, ProjectivePoint)],
+) -> Result<Signature, TypedUsize<SignId>> {
+    let list = commitment_list(signers);
+    let r = group_commitment(msg, &list);
+    let c = challenge(&r, group_pubkey, msg);
+    let signer_indices: Vec<usize> = list.iter().map(|(id, _)| id.as_usize()).collect();
+
+    let mut z = Scalar::ZERO;
+    for (id, share) in shares {
+        let rho_i = binding_factor(*id, msg, &list);
+        let (_, commitments) = list
+            .iter()
+            .find(|(j, _)| j == id)
+            .ok_or(*id)?;
+        let (_, share_pubkey) = share_pubkeys.iter().find(|(j, _)| j == id).ok_or(*id)?;
+        let lambda_i = lagrange_coefficient(id.as_usize(), &signer_indices);
+
+        // z_i·G == D_i + rho_i·E_i + lambda_i·c·(s_i·G)
+        let lhs = ProjectivePoint::GENERATOR * *share.z_i.as_ref();
+        let rhs = *commitments.hiding.as_ref()
+            + *commitments.binding.as_ref() * rho_i
+            + *share_pubkey * (lambda_i * c);
+        if lhs != rhs {
+            return Err(*id);
+        }
+
+        z += *share.z_i.as_ref();
+    }
+
+    Ok(Signature {
+        group_commitment: r.into(),
+        z: z.into(),
+    })
+}
+
+/// Verify a FROST signature against the group public key: `z·G == R + c·Y`.
+pub fn verify(group_pubkey: &ProjectivePoint, msg: &[u8], sig: &Signature) -> bool {
+    let r = *sig.group_commitment.as_ref();
+    let c = challenge(&r, group_pubkey, msg);
+    ProjectivePoint::GENERATOR * *sig.z.as_ref() == r + *group_pubkey * c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::U256;
+
+    /// A 2-of-2 signing run from a degree-1 sharing of the secret `a0`.
+    struct Fixture {
+        msg: Vec<u8>,
+        group_pubkey: ProjectivePoint,
+        ids: Vec<TypedUsize<SignId>>,
+        secrets: Vec<Scalar>,
+        lambdas: Vec<Scalar>,
+        nonces: Vec<SigningNonces>,
+        signers: Vec<(TypedUsize<SignId>, SigningCommitments)>,
+    }
+
+    fn setup() -> Fixture {
+        let a0 = Scalar::reduce(U256::from_u64(0x00ab_cdef));
+        let a1 = Scalar::reduce(U256::from_u64(0x0012_3456));
+        // shares s_j = f(j) for the signing set {1, 2}
+        let one = Scalar::ONE;
+        let two = one + one;
+        let s1 = a0 + a1 * one;
+        let s2 = a0 + a1 * two;
+        // Lagrange coefficients at 0 for {1, 2}: l1 = 2, l2 = -1
+        let lambdas = vec![two, -one];
+
+        // 0-based ids evaluate the sharing polynomial at x = id + 1, i.e. {1, 2}
+        let ids: Vec<_> = [0usize, 1]
+            .into_iter()
+            .map(TypedUsize::from_usize)
+            .collect();
+
+        let mut nonces = Vec::new();
+        let mut signers = Vec::new();
+        for &id in &ids {
+            let (n, c) = round1(&mut rand::rngs::OsRng);
+            nonces.push(n);
+            signers.push((id, c));
+        }
+
+        Fixture {
+            msg: b"frost test message".to_vec(),
+            group_pubkey: ProjectivePoint::GENERATOR * a0,
+            ids,
+            secrets: vec![s1, s2],
+            lambdas,
+            nonces,
+            signers,
+        }
+    }
+
+    fn shares(f: &Fixture) -> Vec<(TypedUsize<SignId>, SignatureShare)> {
+        let list = commitment_list(&f.signers);
+        f.ids
+            .iter()
+            .enumerate()
+            .map(|(i, &id)| {
+                let share = sign_share(
+                    id,
+                    &f.nonces[i],
+                    f.lambdas[i],
+                    &f.secrets[i],
+                    &f.group_pubkey,
+                    &f.msg,
+                    &list,
+                );
+                (id, share)
+            })
+            .collect()
+    }
+
+    fn share_pubkeys(f: &Fixture) -> Vec<(TypedUsize<SignId>, ProjectivePoint)> {
+        f.ids
+            .iter()
+            .zip(&f.secrets)
+            .map(|(&id, s)| (id, ProjectivePoint::GENERATOR * s))
+            .collect()
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let f = setup();
+        let shares = shares(&f);
+        let sig = aggregate(
+            &f.group_pubkey,
+            &f.msg,
+            &f.signers,
+            &shares,
+            &share_pubkeys(&f),
+        )
+        .unwrap();
+        assert!(verify(&f.group_pubkey, &f.msg, &sig));
+    }
+
+    #[test]
+    fn cheating_signer_is_attributed() {
+        let f = setup();
+        let mut shares = shares(&f);
+        // signer at index 1 (id 1, evaluation point x = 2) corrupts its share
+        let bad = *shares[1].1.z_i.as_ref() + Scalar::ONE;
+        shares[1].1 = SignatureShare { z_i: bad.into() };
+
+        let err = aggregate(
+            &f.group_pubkey,
+            &f.msg,
+            &f.signers,
+            &shares,
+            &share_pubkeys(&f),
+        )
+        .unwrap_err();
+        assert_eq!(err, f.ids[1]);
+    }
+}
@@ -0,0 +1,21 @@
+//! Domain-separation tags for the crypto tooling.
+//!
+//! Every hash in the crate is bound to a distinct one-byte tag so that outputs
+//! produced for different purposes can never collide. The tags below were added
+//! for the Schnorr/DKG/ECDH subsystems; they extend the existing tag namespace
+//! (e.g. `Y_I_COMMIT_TAG`) and must stay mutually distinct.
+
+/// FROST per-signer binding factor `rho_i`.
+pub const FROST_RHO_TAG: u8 = 0xf0;
+
+/// FROST Schnorr challenge `c`.
+pub const FROST_CHALLENGE_TAG: u8 = 0xf1;
+
+/// PedPoP proof-of-possession challenge.
+pub const PEDPOP_POP_TAG: u8 = 0xf2;
+
+/// PedPoP encrypted-share keystream.
+pub const PEDPOP_SHARE_ENC_TAG: u8 = 0xf3;
+
+/// ECDH shared-secret KDF.
+pub const ECDH_TAG: u8 = 0xf4;
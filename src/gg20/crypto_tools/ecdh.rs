@@ -0,0 +1,79 @@
+//! ECDH shared-secret derivation over k256, mirroring the secp256k1 `ecdh`
+//! interface.
+//!
+//! Given a local secret scalar and a remote point, the raw shared secret is the
+//! point `shared = sk·PK`. A symmetric key is derived by hashing the compressed
+//! shared point under a fixed domain-separation prefix plus a caller-supplied
+//! tag, so keys derived for distinct purposes never collide. This backs share
+//! encryption in the PedPoP DKG (see [`crate::pedpop`]) and peer-to-peer channel
+//! encryption.
+
+use super::constants;
+use k256::{elliptic_curve::sec1::ToEncodedPoint, ProjectivePoint, Scalar};
+use sha2::{Digest, Sha256};
+
+/// Caller tag used by the default [`derive_shared_secret`] path. The default
+/// path is [`derive_shared_secret_tagged`] with this tag, so a caller passing
+/// the same tag bytes derives the same key.
+const DEFAULT_TAG: &[u8] = b"default";
+
+/// Derive a 32-byte symmetric key from `local_secret·remote_pubkey` under the
+/// default tag.
+pub fn derive_shared_secret(local_secret: &Scalar, remote_pubkey: &ProjectivePoint) -> [u8; 32] {
+    derive_shared_secret_tagged(local_secret, remote_pubkey, DEFAULT_TAG)
+}
+
+/// Like [`derive_shared_secret`] but binds an additional caller-supplied tag into
+/// the KDF, so keys derived for distinct purposes never collide.
+pub fn derive_shared_secret_tagged(
+    local_secret: &Scalar,
+    remote_pubkey: &ProjectivePoint,
+    tag: &[u8],
+) -> [u8; 32] {
+    let shared = *remote_pubkey * local_secret;
+
+    let mut hasher = Sha256::new();
+    hasher.update([constants::ECDH_TAG]);
+    hasher.update((tag.len() as u64).to_le_bytes());
+    hasher.update(tag);
+    hasher.update(shared.to_encoded_point(true).as_bytes());
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::{elliptic_curve::ops::Reduce, U256};
+
+    fn keypair(seed: u64) -> (Scalar, ProjectivePoint) {
+        let sk = Scalar::reduce(U256::from_u64(seed));
+        (sk, ProjectivePoint::GENERATOR * sk)
+    }
+
+    #[test]
+    fn both_parties_agree() {
+        let (sk_a, pk_a) = keypair(0x11);
+        let (sk_b, pk_b) = keypair(0x22);
+
+        // sk_a·PK_b == sk_b·PK_a
+        assert_eq!(
+            derive_shared_secret(&sk_a, &pk_b),
+            derive_shared_secret(&sk_b, &pk_a),
+        );
+    }
+
+    #[test]
+    fn tags_give_distinct_keys() {
+        let (sk_a, _) = keypair(0x11);
+        let (_, pk_b) = keypair(0x22);
+
+        let default = derive_shared_secret(&sk_a, &pk_b);
+        let tagged_one = derive_shared_secret_tagged(&sk_a, &pk_b, b"channel");
+        let tagged_two = derive_shared_secret_tagged(&sk_a, &pk_b, b"shares");
+
+        assert_ne!(default, tagged_one);
+        assert_ne!(tagged_one, tagged_two);
+        // a caller passing the default tag must reproduce the default key
+        assert_eq!(default, derive_shared_secret_tagged(&sk_a, &pk_b, DEFAULT_TAG));
+    }
+}
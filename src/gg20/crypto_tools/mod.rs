@@ -0,0 +1,3 @@
+pub mod constants;
+pub mod ecdh;
+pub mod paillier;
@@ -0,0 +1,6 @@
+pub mod ed25519;
+pub mod frost;
+pub mod gg20;
+pub mod pedpop;
+pub mod protocol;
+pub mod refactor;
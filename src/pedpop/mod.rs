@@ -0,0 +1,262 @@
+//! SimplPedPoP distributed key generation.
+//!
+//! [`crate::gg20::keygen`] bundles the Paillier `EncryptionKeyProof` and
+//! `ZkSetupProof` into round 1 so that ECDSA's MtA has the setup it needs. For
+//! Schnorr/EdDSA threshold use that work is wasted. This module produces a
+//! Paillier-free [`PedPopKeyShare`] in one broadcast round plus encrypted share
+//! delivery, with no Paillier artifacts.
+//!
+//! The gg20 [`SecretKeyShare`](crate::gg20::keygen::SecretKeyShare) embeds a
+//! Paillier `DecryptionKey` in its `ShareSecretInfo`, so a Paillier-free DKG
+//! cannot produce one; this module returns its own share type carrying just the
+//! secret scalar and the group public key, which is all Schnorr signing needs.
+//!
+//! Each participant commits to a degree-`t` polynomial `f_i` as a VSS commitment
+//! `C_i = [f_i,0·G, …, f_i,t·G]` and proves possession of `f_i(0)` with a Schnorr
+//! signature over `C_i,0`, bound to the participant id and session nonce. Shares
+//! `f_i(j)` are delivered encrypted to each peer `j` under an ECDH key derived
+//! from the peers' keygen public keys (see [`crate::gg20::crypto_tools::ecdh`]).
+//! On receipt `j` decrypts each share, checks it against the sender's commitment,
+//! verifies every proof-of-possession, then derives `s_j = Σ_i f_i(j)` and the
+//! group key `Y = Σ_i C_i,0`. Any failure is attributed to the faulting party.
+
+use crate::{
+    collections::TypedUsize,
+    gg20::crypto_tools::{constants, ecdh, k256_serde, vss},
+    sdk::implementer_api::serialize,
+};
+use k256::{
+    elliptic_curve::{ops::Reduce, sec1::ToEncodedPoint},
+    ProjectivePoint, Scalar, U256,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Index type for a DKG participant.
+pub struct KeygenId;
+
+/// The Paillier-free output of the PedPoP DKG: this party's secret share of the
+/// group key, its index, and the shared group public key `Y`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PedPopKeyShare {
+    pub index: TypedUsize<KeygenId>,
+    pub group_public_key: k256_serde::ProjectivePoint,
+    x_i: k256_serde::Scalar,
+}
+
+impl PedPopKeyShare {
+    /// This party's secret share `s_j`.
+    pub fn secret_share(&self) -> &k256_serde::Scalar {
+        &self.x_i
+    }
+}
+
+/// A Schnorr proof-of-possession for the polynomial constant term `f_i(0)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProofOfPossession {
+    pub r: k256_serde::ProjectivePoint,
+    pub z: k256_serde::Scalar,
+}
+
+/// Round-1 broadcast: the VSS commitment and proof-of-possession.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bcast {
+    pub vss_commit: vss::Commit,
+    pub pop: ProofOfPossession,
+}
+
+/// An evaluation `f_i(j)` encrypted to recipient `j` under an ECDH-derived key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncryptedShare {
+    ciphertext: Vec<u8>,
+}
+
+/// Prove possession of the polynomial secret `f_i(0)`, bound to the participant
+/// id and the session nonce.
+fn prove_possession(
+    me: TypedUsize<KeygenId>,
+    session_nonce: &[u8],
+    secret: &Scalar,
+    commit0: &ProjectivePoint,
+    rng: &mut (impl rand::RngCore + rand::CryptoRng),
+) -> ProofOfPossession {
+    let k = Scalar::generate_vartime(rng);
+    let r = ProjectivePoint::GENERATOR * k;
+    let c = pop_challenge(me, session_nonce, commit0, &r);
+    ProofOfPossession {
+        r: r.into(),
+        z: (k + c * secret).into(),
+    }
+}
+
+/// Verify a proof-of-possession against the claimed constant-term commitment.
+fn verify_possession(
+    who: TypedUsize<KeygenId>,
+    session_nonce: &[u8],
+    commit0: &ProjectivePoint,
+    pop: &ProofOfPossession,
+) -> bool {
+    let c = pop_challenge(who, session_nonce, commit0, pop.r.as_ref());
+    ProjectivePoint::GENERATOR * *pop.z.as_ref() == *pop.r.as_ref() + *commit0 * c
+}
+
+fn pop_challenge(
+    who: TypedUsize<KeygenId>,
+    session_nonce: &[u8],
+    commit0: &ProjectivePoint,
+    r: &ProjectivePoint,
+) -> Scalar {
+    let mut hasher = Sha256::new();
+    hasher.update([constants::PEDPOP_POP_TAG]);
+    hasher.update(who.as_usize().to_le_bytes());
+    hasher.update(session_nonce);
+    hasher.update(commit0.to_encoded_point(true).as_bytes());
+    hasher.update(r.to_encoded_point(true).as_bytes());
+    Scalar::reduce(U256::from_be_slice(&hasher.finalize()))
+}
+
+/// Round 1: sample a degree-`threshold` polynomial, commit to it, and prove
+/// possession of its constant term. Returns the secret VSS (kept for share
+/// delivery) and the broadcast.
+pub fn start(
+    me: TypedUsize<KeygenId>,
+    threshold: usize,
+    session_nonce: &[u8],
+    rng: &mut (impl rand::RngCore + rand::CryptoRng),
+) -> (vss::Vss, Bcast) {
+    let u_i_vss = vss::Vss::new(threshold);
+    let vss_commit = u_i_vss.commit();
+    let commit0 = *vss_commit.secret_commit();
+
+    let pop = prove_possession(me, session_nonce, u_i_vss.get_secret(), &commit0, rng);
+
+    (u_i_vss, Bcast { vss_commit, pop })
+}
+
+/// Encrypt the evaluation `share` from `sender` to `recipient` under an
+/// ECDH-derived key, for delivery in the share-distribution step.
+///
+/// The key is bound to the session nonce and the ordered `(sender, recipient)`
+/// pair, so the symmetric keystream is unique per direction and per DKG run —
+/// without this the static ECDH secret would produce a two-time pad across runs
+/// and between the two directions of a pair. The keystream is unauthenticated:
+/// integrity of the plaintext rests entirely on the [`vss::Commit::validate_share`]
+/// check the recipient performs in [`finish`], which rejects (and attributes)
+/// any tampered or malformed share.
+pub fn encrypt_share(
+    local_secret: &Scalar,
+    sender: TypedUsize<KeygenId>,
+    recipient: TypedUsize<KeygenId>,
+    recipient_pubkey: &ProjectivePoint,
+    session_nonce: &[u8],
+    share: &vss::Share,
+) -> EncryptedShare {
+    let key = share_key(local_secret, recipient_pubkey, sender, recipient, session_nonce);
+    let mut ciphertext = serialize_share(share);
+    xor_keystream(&key, &mut ciphertext);
+    EncryptedShare { ciphertext }
+}
+
+/// Decrypt an [`EncryptedShare`] sent by `sender` to us (`recipient`).
+fn decrypt_share(
+    local_secret: &Scalar,
+    sender: TypedUsize<KeygenId>,
+    recipient: TypedUsize<KeygenId>,
+    sender_pubkey: &ProjectivePoint,
+    session_nonce: &[u8],
+    encrypted: &EncryptedShare,
+) -> Option<vss::Share> {
+    let key = share_key(local_secret, sender_pubkey, sender, recipient, session_nonce);
+    let mut plaintext = encrypted.ciphertext.clone();
+    xor_keystream(&key, &mut plaintext);
+    bincode::deserialize(&plaintext).ok()
+}
+
+/// Derive the symmetric key for the `(sender -> recipient)` channel in this
+/// session, binding the direction and session nonce into the ECDH KDF.
+fn share_key(
+    local_secret: &Scalar,
+    peer_pubkey: &ProjectivePoint,
+    sender: TypedUsize<KeygenId>,
+    recipient: TypedUsize<KeygenId>,
+    session_nonce: &[u8],
+) -> [u8; 32] {
+    let mut context = Vec::with_capacity(16 + session_nonce.len());
+    context.extend_from_slice(&sender.as_usize().to_le_bytes());
+    context.extend_from_slice(&recipient.as_usize().to_le_bytes());
+    context.extend_from_slice(session_nonce);
+    ecdh::derive_shared_secret_tagged(local_secret, peer_pubkey, &context)
+}
+
+/// On receipt of all round-1 broadcasts and the shares addressed to us, verify
+/// every commitment, share, and proof-of-possession, then derive our secret
+/// share and the group public key, returning a [`PedPopKeyShare`]. Any failure
+/// is attributed to the faulting party via the returned [`TypedUsize`].
+pub fn finish(
+    me: TypedUsize<KeygenId>,
+    my_ecdh_secret: &Scalar,
+    session_nonce: &[u8],
+    bcasts: &[(TypedUsize<KeygenId>, Bcast)],
+    ecdh_pubkeys: &[(TypedUsize<KeygenId>, ProjectivePoint)],
+    encrypted_shares_to_me: &[(TypedUsize<KeygenId>, EncryptedShare)],
+) -> Result<PedPopKeyShare, TypedUsize<KeygenId>> {
+    let mut s_j = Scalar::ZERO;
+    let mut group_pubkey = ProjectivePoint::IDENTITY;
+
+    for (i, bcast) in bcasts {
+        if !verify_possession(*i, session_nonce, bcast.vss_commit.secret_commit(), &bcast.pop) {
+            return Err(*i);
+        }
+
+        let sender_pubkey = find(ecdh_pubkeys, *i).ok_or(*i)?;
+        let encrypted = find(encrypted_shares_to_me, *i).ok_or(*i)?;
+        let share =
+            decrypt_share(my_ecdh_secret, *i, me, sender_pubkey, session_nonce, encrypted)
+                .ok_or(*i)?;
+
+        // the delivered share must be our own evaluation point `f_i(me)`
+        if share.get_index() != me.as_usize() {
+            return Err(*i);
+        }
+
+        // f_i(x)·G == Σ_k (x^k)·C_i,k at the evaluation point x = me + 1
+        if !bcast.vss_commit.validate_share(&share) {
+            return Err(*i);
+        }
+
+        s_j += share.get_scalar();
+        group_pubkey += *bcast.vss_commit.secret_commit();
+    }
+
+    Ok(PedPopKeyShare {
+        index: me,
+        group_public_key: group_pubkey.into(),
+        x_i: s_j.into(),
+    })
+}
+
+/// Look up the value paired with `id`.
+fn find<T>(items: &[(TypedUsize<KeygenId>, T)], id: TypedUsize<KeygenId>) -> Option<&T> {
+    items.iter().find(|(j, _)| *j == id).map(|(_, v)| v)
+}
+
+/// Serialize a VSS share to bytes for encryption.
+fn serialize_share(share: &vss::Share) -> Vec<u8> {
+    // share serialization is infallible; fall back to an empty buffer that will
+    // fail to decrypt-and-validate (and thus be attributed) if it ever is not.
+    serialize(share).unwrap_or_default()
+}
+
+/// XOR `data` in place with a SHA-256 counter-mode keystream derived from `key`.
+fn xor_keystream(key: &[u8; 32], data: &mut [u8]) {
+    for (counter, block) in data.chunks_mut(32).enumerate() {
+        let mut hasher = Sha256::new();
+        hasher.update([constants::PEDPOP_SHARE_ENC_TAG]);
+        hasher.update(key);
+        hasher.update((counter as u64).to_le_bytes());
+        let pad = hasher.finalize();
+        for (b, p) in block.iter_mut().zip(pad.iter()) {
+            *b ^= p;
+        }
+    }
+}
@@ -2,8 +2,9 @@ use crate::zkp::pedersen;
 
 use super::{Sign, Status};
 use curv::{
+    arithmetic::traits::{Converter, Modulo},
     elliptic::curves::traits::{ECPoint, ECScalar},
-    FE,
+    BigInt, FE, GE,
 };
 use serde::{Deserialize, Serialize};
 
@@ -80,4 +81,72 @@ impl Sign {
             },
         )
     }
+
+    /// Assemble the final recoverable signature from the aggregated scalar pair
+    /// `(r, s)` and the signature randomizer `R` (`r5state.ecdsa_randomizer`).
+    /// `s` is the sum of every party's `ecdsa_sig_summand`. No round of the
+    /// sign protocol calls this function.
+    pub(super) fn recoverable_signature(&self, r: FE, s: FE) -> RecoverableSignature {
+        let r5state = self.r5state.as_ref().unwrap();
+        RecoverableSignature::new(&r5state.ecdsa_randomizer, r, s)
+    }
+}
+
+/// A recoverable ECDSA signature `(r, s, recid)`.
+///
+/// The recovery id lets `ecrecover`-style consumers recover the signing public
+/// key from the signature alone. It is built from two bits of the randomizer
+/// `R`: `bit0` is the parity of `R.y` and `bit1` is set when `R.x` overflowed
+/// the curve order when reduced mod `q` to form `r`. Low-S normalization
+/// replaces `s` with `q - s` when `s > q/2` and flips `bit0`.
+#[derive(Debug, Clone)]
+pub struct RecoverableSignature {
+    pub r: FE,
+    pub s: FE,
+    pub recid: u8,
+}
+
+impl RecoverableSignature {
+    pub fn new(ecdsa_randomizer: &GE, r: FE, s: FE) -> Self {
+        let q = FE::q();
+        let two = BigInt::from(2);
+
+        let bit0 = ecdsa_randomizer.y_coor().unwrap().modulus(&two) == BigInt::one();
+        let bit1 = ecdsa_randomizer.x_coor().unwrap() >= q;
+
+        // low-S normalization keeps `s` in the lower half of the scalar field
+        let (s, bit0) = if s.to_big_int() > q.div_floor(&two) {
+            (ECScalar::from(&(q - s.to_big_int())), !bit0)
+        } else {
+            (s, bit0)
+        };
+
+        let recid = ((bit1 as u8) << 1) | (bit0 as u8);
+
+        Self { r, s, recid }
+    }
+
+    /// The 64-byte `r || s` encoding, matching the non-recoverable signature.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        write_fe_be(&mut bytes[0..32], &self.r);
+        write_fe_be(&mut bytes[32..64], &self.s);
+        bytes
+    }
+
+    /// The 65-byte `r || s || v` recoverable encoding.
+    pub fn to_bytes_recoverable(&self) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[..64].copy_from_slice(&self.to_bytes());
+        bytes[64] = self.recid;
+        bytes
+    }
+}
+
+/// Write `scalar` as a 32-byte big-endian integer into `dst`, left-padding with
+/// zeros. `dst` must be exactly 32 bytes long.
+fn write_fe_be(dst: &mut [u8], scalar: &FE) {
+    let bytes = scalar.to_big_int().to_vec();
+    dst[..32 - bytes.len()].fill(0);
+    dst[32 - bytes.len()..].copy_from_slice(&bytes);
 }
\ No newline at end of file